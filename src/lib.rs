@@ -49,8 +49,39 @@ extern crate proptest;
 #[macro_use]
 extern crate std;
 
+// Opt-in interop with the `num-traits` ecosystem: this crate stays `no_std` and dependency-free
+// by default, but code already generic over `num_traits::ToPrimitive` can pull a strength-reduced
+// divisor's value out with `.get()` through that same trait, instead of needing a one-off
+// conversion per `StrengthReduced*` type. Every `StrengthReduced*` type below implements this the
+// same way: forward to `.get()`. This only covers `ToPrimitive`, not the full `Integer`/`PrimInt`
+// surface -- these structs represent a divisor to divide *by*, not a number to do arithmetic *on*,
+// so most of `Integer`/`PrimInt` doesn't apply to them.
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+
 use core::ops::{Div, Rem};
 
+/// A strength-reduced divisor for some primitive integer type.
+///
+/// Implemented by every concrete `StrengthReduced*` type in this crate (unsigned, signed, and
+/// branch-free alike), so generic code can be written once against "a strength-reduced divisor"
+/// instead of being duplicated per width. The `Primitive: Div<Self> + Rem<Self>` bound on the
+/// associated type is what lets that generic code actually write `numerator / divisor` and
+/// `numerator % divisor`, the same way callers of the concrete types do.
+pub trait StrengthReducedDivisor: Copy {
+    /// The primitive integer type this divisor reduces division and modulo for.
+    type Primitive: Copy + Div<Self, Output = Self::Primitive> + Rem<Self, Output = Self::Primitive>;
+
+    /// Creates a new divisor instance. See the concrete type's `new` for the exact panic conditions.
+    fn new(divisor: Self::Primitive) -> Self;
+
+    /// Simultaneous truncated integer division and modulus. Returns `(quotient, remainder)`.
+    fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive);
+
+    /// Retrieve the value used to create this struct.
+    fn get(&self) -> Self::Primitive;
+}
+
 macro_rules! strength_reduced_impl {
     ($struct_name:ident, $primitive_type:ident, $intermediate_type:ident, $bit_width:expr) => (
         /// Implements unsigned division and modulo via mutiplication and shifts.
@@ -59,7 +90,7 @@ macro_rules! strength_reduced_impl {
         /// this version will be several times faster than naive division.
         #[derive(Clone, Copy, Debug)]
         pub struct $struct_name {
-            multiplier: $primitive_type,
+            multiplier: $intermediate_type,
             divisor: $primitive_type,
             shift_value: u8,
         }
@@ -69,20 +100,25 @@ macro_rules! strength_reduced_impl {
             /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for divison and remainders inside the loop.
             ///
             /// # Panics:
-            /// 
+            ///
             /// Panics if `divisor` is 0
             #[inline]
             pub fn new(divisor: $primitive_type) -> Self {
                 assert!(divisor > 0);
-                if divisor == 1 { 
+                if divisor == 1 {
                     Self{ multiplier: 1, divisor, shift_value: 0 }
                 } else {
                     let big_divisor = divisor as $intermediate_type;
                     let trailing_zeros = big_divisor.next_power_of_two().trailing_zeros();
-                    let shift_size = trailing_zeros + $bit_width - 1;
+                    // The round-up magic number theorem (Hacker's Delight Theorem 10-2) needs a
+                    // full `$bit_width + trailing_zeros` of shift to guarantee exactness; that can
+                    // push `1 << shift_size` one bit past what `$intermediate_type` can hold on its
+                    // own (it's only double `$primitive_type`'s width), so the division below runs
+                    // in `u128` to leave enough headroom before narrowing back down.
+                    let shift_size = trailing_zeros + $bit_width;
 
                     Self {
-                        multiplier: (((1 << shift_size) + big_divisor - 1) / big_divisor) as $primitive_type,
+                        multiplier: (((1u128 << shift_size) + big_divisor as u128 - 1) / big_divisor as u128) as $intermediate_type,
                         divisor,
                         shift_value: shift_size as u8
                     }
@@ -103,6 +139,64 @@ macro_rules! strength_reduced_impl {
             pub fn get(&self) -> $primitive_type {
                 self.divisor
             }
+
+            /// Divides every element of `slice` by this divisor, in place.
+            ///
+            /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+            /// with the `#[inline]` `Div` impl applied to each element in turn.
+            #[inline]
+            pub fn div_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element / *self;
+                }
+            }
+
+            /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+            #[inline]
+            pub fn rem_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element % *self;
+                }
+            }
+
+            /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `quotient` or `remainder` don't have the same length as `src`
+            #[inline]
+            pub fn div_rem_into(&self, src: &[$primitive_type], quotient: &mut [$primitive_type], remainder: &mut [$primitive_type]) {
+                assert_eq!(src.len(), quotient.len());
+                assert_eq!(src.len(), remainder.len());
+                for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+                    let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+                    *q = numerator_quotient;
+                    *r = numerator_remainder;
+                }
+            }
+
+            /// Computes `ceil(numerator / self)`, the quotient rounded up to the nearest integer,
+            /// without needing a separate hardware division: just `div_rem` plus "add 1 if the
+            /// remainder was nonzero".
+            #[inline]
+            pub fn div_ceil(&self, numerator: $primitive_type) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, *self);
+                if remainder > 0 { quotient + 1 } else { quotient }
+            }
+
+            /// Rounds `numerator` up to the next multiple of this divisor.
+            #[inline]
+            pub fn next_multiple_of(&self, numerator: $primitive_type) -> $primitive_type {
+                self.div_ceil(numerator) * self.get()
+            }
+
+            /// Simultaneous floored integer division and modulus, for callers that want the
+            /// `num-integer` `div_mod_floor` call signature. Since this divisor is always
+            /// positive, this is identical to `div_rem`.
+            #[inline]
+            pub fn div_mod_floor(&self, numerator: $primitive_type) -> ($primitive_type, $primitive_type) {
+                Self::div_rem(numerator, *self)
+            }
         }
 
         impl Div<$struct_name> for $primitive_type {
@@ -110,7 +204,9 @@ macro_rules! strength_reduced_impl {
 
             #[inline]
             fn div(self, rhs: $struct_name) -> Self::Output {
-                let multiplied = (self as $intermediate_type) * (rhs.multiplier as $intermediate_type);
+                // see the headroom comment on `new()`: this multiply needs a bit more room than
+                // `$intermediate_type` has to spare in the worst case, so it runs in `u128`.
+                let multiplied = (self as u128) * (rhs.multiplier as u128);
                 let shifted = multiplied >> rhs.shift_value;
                 shifted as $primitive_type
             }
@@ -125,6 +221,39 @@ macro_rules! strength_reduced_impl {
                 self - quotient * rhs.divisor
             }
         }
+
+        impl StrengthReducedDivisor for $struct_name {
+            type Primitive = $primitive_type;
+
+            #[inline]
+            fn new(divisor: Self::Primitive) -> Self {
+                Self::new(divisor)
+            }
+
+            #[inline]
+            fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+                Self::div_rem(numerator, denom)
+            }
+
+            #[inline]
+            fn get(&self) -> Self::Primitive {
+                Self::get(self)
+            }
+        }
+
+        // see the `num-traits` interop note near the top of the file
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $struct_name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.get().to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.get().to_u64()
+            }
+        }
     )
 }
 
@@ -153,16 +282,32 @@ macro_rules! strength_reduced_impl_intermediate_multiplier {
             #[inline]
             pub fn new(divisor: $primitive_type) -> Self {
                 assert!(divisor > 0);
-                if divisor == 1 { 
+                if divisor == 1 {
                     Self{ multiplier: 1 << $bit_width, divisor, shift_value: 0 }
                 } else {
                     let big_divisor = divisor as $intermediate_type;
                     let trailing_zeros = big_divisor.next_power_of_two().trailing_zeros();
+                    // The round-up magic number theorem (Hacker's Delight Theorem 10-2) needs a
+                    // full `$bit_width + trailing_zeros` of shift to guarantee exactness; that can
+                    // reach all the way up to `2 * $bit_width`, too wide for `1 << shift_size` to
+                    // fit in `$intermediate_type` (or even in `u128`, for `$bit_width` of 64). So
+                    // build `2^shift_size` as a 256-bit (hi, lo) `u128` pair instead -- same
+                    // technique as `StrengthReducedU128::new`, just relative to `$bit_width`.
+                    let shift_size = trailing_zeros + $bit_width;
+                    let (numerator_hi, numerator_lo) = if shift_size >= 128 {
+                        (1u128 << (shift_size - 128), 0u128)
+                    } else {
+                        (0u128, 1u128 << shift_size)
+                    };
+                    let divisor_u128 = big_divisor as u128;
+                    // round up: add (divisor - 1) before dividing
+                    let (numerator_lo, carry) = numerator_lo.overflowing_add(divisor_u128 - 1);
+                    let numerator_hi = numerator_hi + carry as u128;
 
                     Self {
-                        multiplier: ((1 << trailing_zeros + $bit_width - 1) + big_divisor - 1) / big_divisor,
+                        multiplier: divide_256_by_128(numerator_hi, numerator_lo, divisor_u128).0 as $intermediate_type,
                         divisor,
-                        shift_value: (trailing_zeros - 1) as u8
+                        shift_value: trailing_zeros as u8
                     }
                 }
             }
@@ -181,6 +326,64 @@ macro_rules! strength_reduced_impl_intermediate_multiplier {
             pub fn get(&self) -> $primitive_type {
                 self.divisor
             }
+
+            /// Divides every element of `slice` by this divisor, in place.
+            ///
+            /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+            /// with the `#[inline]` `Div` impl applied to each element in turn.
+            #[inline]
+            pub fn div_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element / *self;
+                }
+            }
+
+            /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+            #[inline]
+            pub fn rem_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element % *self;
+                }
+            }
+
+            /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `quotient` or `remainder` don't have the same length as `src`
+            #[inline]
+            pub fn div_rem_into(&self, src: &[$primitive_type], quotient: &mut [$primitive_type], remainder: &mut [$primitive_type]) {
+                assert_eq!(src.len(), quotient.len());
+                assert_eq!(src.len(), remainder.len());
+                for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+                    let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+                    *q = numerator_quotient;
+                    *r = numerator_remainder;
+                }
+            }
+
+            /// Computes `ceil(numerator / self)`, the quotient rounded up to the nearest integer,
+            /// without needing a separate hardware division: just `div_rem` plus "add 1 if the
+            /// remainder was nonzero".
+            #[inline]
+            pub fn div_ceil(&self, numerator: $primitive_type) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, *self);
+                if remainder > 0 { quotient + 1 } else { quotient }
+            }
+
+            /// Rounds `numerator` up to the next multiple of this divisor.
+            #[inline]
+            pub fn next_multiple_of(&self, numerator: $primitive_type) -> $primitive_type {
+                self.div_ceil(numerator) * self.get()
+            }
+
+            /// Simultaneous floored integer division and modulus, for callers that want the
+            /// `num-integer` `div_mod_floor` call signature. Since this divisor is always
+            /// positive, this is identical to `div_rem`.
+            #[inline]
+            pub fn div_mod_floor(&self, numerator: $primitive_type) -> ($primitive_type, $primitive_type) {
+                Self::div_rem(numerator, *self)
+            }
         }
 
         impl Div<$struct_name> for $primitive_type {
@@ -188,8 +391,17 @@ macro_rules! strength_reduced_impl_intermediate_multiplier {
 
             #[inline]
             fn div(self, rhs: $struct_name) -> Self::Output {
-                let multiplied = ((self as $intermediate_type) * rhs.multiplier) >> $bit_width;
-                (multiplied as $primitive_type) >> rhs.shift_value
+                // `self * rhs.multiplier` can occasionally need one more bit than `u128` has (the
+                // same headroom issue as `new()`, here hitting the *product* instead of the
+                // multiplier itself); since that product is always < 2 * 2^128, the overflow flag
+                // from `overflowing_mul` is exactly that missing top bit.
+                let (low, overflowed) = (self as u128).overflowing_mul(rhs.multiplier as u128);
+                let shifted = if overflowed {
+                    (1u128 << (128 - $bit_width)) | (low >> $bit_width)
+                } else {
+                    low >> $bit_width
+                };
+                (shifted >> rhs.shift_value) as $primitive_type
             }
         }
 
@@ -202,139 +414,1424 @@ macro_rules! strength_reduced_impl_intermediate_multiplier {
                 self - quotient * rhs.divisor
             }
         }
-    )
-}
 
-// We have two separate macros because the two bigger versions seem to want to be optimized in a slightly different way than the two smaller ones
-strength_reduced_impl!(StrengthReducedU8, u8, u16, 8);
-strength_reduced_impl!(StrengthReducedU16, u16, u32, 16);
-strength_reduced_impl_intermediate_multiplier!(StrengthReducedU32, u32, u64, 32);
-strength_reduced_impl_intermediate_multiplier!(StrengthReducedU64, u64, u128, 64);
+        impl StrengthReducedDivisor for $struct_name {
+            type Primitive = $primitive_type;
 
-// Our definition for usize will depend on how big usize is
-#[cfg(target_pointer_width = "16")]
-strength_reduced_impl!(StrengthReducedUsize, usize, u32, 16);
-#[cfg(target_pointer_width = "32")]
-strength_reduced_impl_intermediate_multiplier!(StrengthReducedUsize, usize, u64, 32);
-#[cfg(target_pointer_width = "64")]
-strength_reduced_impl_intermediate_multiplier!(StrengthReducedUsize, usize, u128, 64);
+            #[inline]
+            fn new(divisor: Self::Primitive) -> Self {
+                Self::new(divisor)
+            }
 
+            #[inline]
+            fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+                Self::div_rem(numerator, denom)
+            }
 
+            #[inline]
+            fn get(&self) -> Self::Primitive {
+                Self::get(self)
+            }
+        }
 
+        // see the `num-traits` interop note near the top of the file
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $struct_name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.get().to_i64()
+            }
 
-#[cfg(test)]
-mod unit_tests {
-    use super::*;
-    use proptest::test_runner::Config;
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.get().to_u64()
+            }
+        }
+    )
+}
 
-    macro_rules! reduction_test {
-        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
-            #[test]
-            fn $test_name() {
-                let max = core::$primitive_type::MAX;
-                let divisors = [1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
-                let numerators = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
+// Signed division truncates toward zero instead of flooring, so it needs its own magic-number
+// derivation (Hacker's Delight's signed division algorithm) instead of the unsigned macros above.
+macro_rules! strength_reduced_signed_impl {
+    ($struct_name:ident, $primitive_type:ident, $unsigned_type:ident, $intermediate_type:ident, $bit_width:expr) => (
+        /// Implements signed truncating division and modulo via mutiplication and shifts.
+        ///
+        /// Creating a an instance of this struct is more expensive than a single division, but if the division is repeated,
+        /// this version will be several times faster than naive division.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $struct_name {
+            multiplier: $primitive_type,
+            divisor: $primitive_type,
+            shift_value: u8,
+        }
+        impl $struct_name {
+            /// Creates a new divisor instance.
+            ///
+            /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for divison and remainders inside the loop.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `divisor` is 0
+            #[inline]
+            pub fn new(divisor: $primitive_type) -> Self {
+                assert!(divisor != 0);
+                if divisor == 1 {
+                    Self{ multiplier: 1, divisor, shift_value: 0 }
+                } else if divisor == -1 {
+                    Self{ multiplier: -1, divisor, shift_value: 0 }
+                } else {
+                    let ad: $unsigned_type = divisor.unsigned_abs();
+                    let two_pow_w_minus_1: $unsigned_type = 1 << ($bit_width - 1);
+                    let t: $unsigned_type = two_pow_w_minus_1.wrapping_add(if divisor < 0 { 1 } else { 0 });
+                    let anc: $unsigned_type = t.wrapping_sub(1).wrapping_sub(t % ad);
 
-                for &divisor in &divisors {
-                    let reduced_divisor = $struct_name::new(divisor);
-                    for &numerator in &numerators {
-                        let expected_div = numerator / divisor;
-                        let expected_rem = numerator % divisor;
+                    let mut p: u32 = $bit_width - 1;
+                    let mut q1: $unsigned_type = two_pow_w_minus_1 / anc;
+                    let mut r1: $unsigned_type = two_pow_w_minus_1.wrapping_sub(q1.wrapping_mul(anc));
+                    let mut q2: $unsigned_type = two_pow_w_minus_1 / ad;
+                    let mut r2: $unsigned_type = two_pow_w_minus_1.wrapping_sub(q2.wrapping_mul(ad));
 
-                        let reduced_div = numerator / reduced_divisor;
-                        let reduced_rem = numerator % reduced_divisor;
+                    loop {
+                        p += 1;
+                        q1 = q1.wrapping_mul(2);
+                        r1 = r1.wrapping_mul(2);
+                        if r1 >= anc {
+                            q1 = q1.wrapping_add(1);
+                            r1 = r1.wrapping_sub(anc);
+                        }
+                        q2 = q2.wrapping_mul(2);
+                        r2 = r2.wrapping_mul(2);
+                        if r2 >= ad {
+                            q2 = q2.wrapping_add(1);
+                            r2 = r2.wrapping_sub(ad);
+                        }
+                        let delta = ad.wrapping_sub(r2);
+                        if q1 > delta || (q1 == delta && r1 != 0) {
+                            break;
+                        }
+                    }
 
-                        let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                    let mut magic = q2.wrapping_add(1) as $primitive_type;
+                    if divisor < 0 {
+                        magic = magic.wrapping_neg();
+                    }
 
-                        assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                        assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
-                        assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                        assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    Self {
+                        multiplier: magic,
+                        divisor,
+                        shift_value: (p - $bit_width) as u8,
                     }
                 }
             }
-        )
-    }
 
-    reduction_test!(test_strength_reduced_u8, StrengthReducedU8, u8);
-    reduction_test!(test_strength_reduced_u16, StrengthReducedU16, u16);
-    reduction_test!(test_strength_reduced_u32, StrengthReducedU32, u32);
-    reduction_test!(test_strength_reduced_u64, StrengthReducedU64, u64);
-    reduction_test!(test_strength_reduced_usize, StrengthReducedUsize, usize);
+            /// Simultaneous truncated integer division and modulus.
+            /// Returns `(quotient, remainder)`.
+            #[inline]
+            pub fn div_rem(numerator: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
+                let quotient = numerator / denom;
+                let remainder = numerator - quotient * denom.divisor;
+                (quotient, remainder)
+            }
 
-    macro_rules! reduction_proptest {
-        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
-            mod $test_name {
-                use super::*;
-                use proptest::sample::select;
+            /// Retrieve the value used to create this struct
+            #[inline]
+            pub fn get(&self) -> $primitive_type {
+                self.divisor
+            }
 
-                fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
-                    let reduced_divisor = $struct_name::new(divisor);
-                    let expected_div = numerator / divisor;
-                    let expected_rem = numerator % divisor;
-                    let reduced_div = numerator / reduced_divisor;
-                    let reduced_rem = numerator % reduced_divisor;
-                    assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                    assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
-                    let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
-                    assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                    assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+            /// Divides every element of `slice` by this divisor, in place.
+            ///
+            /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+            /// with the `#[inline]` `Div` impl applied to each element in turn.
+            #[inline]
+            pub fn div_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element / *self;
                 }
+            }
 
+            /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+            #[inline]
+            pub fn rem_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element % *self;
+                }
+            }
 
+            /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `quotient` or `remainder` don't have the same length as `src`
+            #[inline]
+            pub fn div_rem_into(&self, src: &[$primitive_type], quotient: &mut [$primitive_type], remainder: &mut [$primitive_type]) {
+                assert_eq!(src.len(), quotient.len());
+                assert_eq!(src.len(), remainder.len());
+                for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+                    let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+                    *q = numerator_quotient;
+                    *r = numerator_remainder;
+                }
+            }
+        }
 
-                proptest! {
-                    #![proptest_config(Config::with_cases(100_000))]
-
-                    #[test]
-                    fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..core::$primitive_type::MAX, numerator in 0..core::$primitive_type::MAX) {
-                        assert_div_rem_equivalence(divisor, numerator);
-                    }
+        impl Div<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
 
-                    #[test]
-                    fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
-                            divisor in 1..core::$primitive_type::MAX,
-                            numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX])) {
-                        assert_div_rem_equivalence(divisor, numerator);
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                if rhs.divisor == 1 {
+                    self
+                } else if rhs.divisor == -1 {
+                    -self
+                } else {
+                    let multiplied = ((self as $intermediate_type) * (rhs.multiplier as $intermediate_type)) >> $bit_width;
+                    let mut quotient = multiplied as $primitive_type;
+                    if rhs.divisor > 0 && rhs.multiplier < 0 {
+                        quotient = quotient.wrapping_add(self);
                     }
-
-                    #[test]
-                    fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
-                            divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX]),
-                            numerator in 0..core::$primitive_type::MAX) {
-                        assert_div_rem_equivalence(divisor, numerator);
+                    if rhs.divisor < 0 && rhs.multiplier > 0 {
+                        quotient = quotient.wrapping_sub(self);
                     }
+                    quotient >>= rhs.shift_value;
+                    quotient.wrapping_add(((quotient as $unsigned_type) >> ($bit_width - 1)) as $primitive_type)
                 }
             }
-        )
-    }
+        }
 
-    reduction_proptest!(strength_reduced_u8, StrengthReducedU8, u8);
-    reduction_proptest!(strength_reduced_u16, StrengthReducedU16, u16);
-    reduction_proptest!(strength_reduced_u32, StrengthReducedU32, u32);
-    reduction_proptest!(strength_reduced_u64, StrengthReducedU64, u64);
-    reduction_proptest!(strength_reduced_usize, StrengthReducedUsize, usize);
+        impl Rem<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
 
-    macro_rules! reduction_spot_test {
-        ($test_name:ident, $struct_name:ident, $divisor:expr, $numerator:expr) => (
-            #[test]
-            fn $test_name() {
-                let divisor = $divisor;
-                let numerator = $numerator;
-                let reduced_divisor = $struct_name::new(divisor);
-                let expected_div = numerator / divisor;
-                let expected_rem = numerator % divisor;
-                let reduced_div = numerator / reduced_divisor;
-                let reduced_rem = numerator % reduced_divisor;
-                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
-                assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
-                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
-                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
-            }
-        )
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                let quotient = self / rhs;
+                self - quotient * rhs.divisor
+            }
+        }
+
+        impl StrengthReducedDivisor for $struct_name {
+            type Primitive = $primitive_type;
+
+            #[inline]
+            fn new(divisor: Self::Primitive) -> Self {
+                Self::new(divisor)
+            }
+
+            #[inline]
+            fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+                Self::div_rem(numerator, denom)
+            }
+
+            #[inline]
+            fn get(&self) -> Self::Primitive {
+                Self::get(self)
+            }
+        }
+
+        // see the `num-traits` interop note near the top of the file
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $struct_name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.get().to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.get().to_u64()
+            }
+        }
+    )
+}
+
+// Branch-free divider, modeled on libdivide's branchfree algorithm: the exact multiplier is one bit
+// wider than the primitive type, so this stores only its low `$bit_width` bits and makes up for the
+// dropped leading bit with an unconditional averaging step in the division hot path.
+// This lets the compiler auto-vectorize `a[i] / divisor` with no per-element branches.
+//
+// divisor == 1 can't be represented (the multiplier/shift pair degenerates), so new() panics for it;
+// callers that might see a divisor of 1 should special-case it themselves before constructing this.
+macro_rules! strength_reduced_branchfree_impl {
+    ($struct_name:ident, $primitive_type:ident, $intermediate_type:ident, $bit_width:expr) => (
+        /// Implements unsigned division and modulo via multiplication and shifts, using a divider representation
+        /// with no data-dependent branches in the hot path. This makes it a good fit for vectorized (SIMD) loops,
+        /// at the cost of not being able to represent a divisor of 1.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $struct_name {
+            multiplier: $primitive_type,
+            divisor: $primitive_type,
+            shift_value: u8,
+        }
+        impl $struct_name {
+            /// Creates a new divisor instance.
+            ///
+            /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for divison and remainders inside the loop.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `divisor` is 0 or 1
+            #[inline]
+            pub fn new(divisor: $primitive_type) -> Self {
+                assert!(divisor > 1, "branch-free division cannot represent a divisor of 0 or 1");
+
+                let floor_log2 = $bit_width - 1 - divisor.leading_zeros();
+
+                if divisor.is_power_of_two() {
+                    Self {
+                        multiplier: 0,
+                        divisor,
+                        shift_value: (floor_log2 - 1) as u8,
+                    }
+                } else {
+                    // ceil(2^(bit_width + shift + 1) / divisor), computed without ever forming
+                    // 2^(2 * bit_width) directly, since that power of two doesn't fit in $intermediate_type
+                    let half = (1 as $intermediate_type) << ($bit_width + floor_log2);
+                    let big_divisor = divisor as $intermediate_type;
+                    let half_quotient = half / big_divisor;
+                    let half_remainder = half % big_divisor;
+                    let full_remainder = half_remainder * 2;
+                    let full_quotient = half_quotient * 2 + if full_remainder >= big_divisor { 1 } else { 0 };
+                    let final_remainder = if full_remainder >= big_divisor { full_remainder - big_divisor } else { full_remainder };
+                    let proposed_multiplier = if final_remainder > 0 { full_quotient + 1 } else { full_quotient };
+
+                    Self {
+                        multiplier: proposed_multiplier.wrapping_sub(1 << $bit_width) as $primitive_type,
+                        divisor,
+                        shift_value: floor_log2 as u8,
+                    }
+                }
+            }
+
+            /// Simultaneous truncated integer division and modulus.
+            /// Returns `(quotient, remainder)`.
+            #[inline]
+            pub fn div_rem(numerator: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
+                let quotient = numerator / denom;
+                let remainder = numerator - quotient * denom.divisor;
+                (quotient, remainder)
+            }
+
+            /// Retrieve the value used to create this struct
+            #[inline]
+            pub fn get(&self) -> $primitive_type {
+                self.divisor
+            }
+
+            /// Divides every element of `slice` by this divisor, in place.
+            ///
+            /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+            /// with the `#[inline]` `Div` impl applied to each element in turn.
+            #[inline]
+            pub fn div_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element / *self;
+                }
+            }
+
+            /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+            #[inline]
+            pub fn rem_slice(&self, slice: &mut [$primitive_type]) {
+                for element in slice.iter_mut() {
+                    *element = *element % *self;
+                }
+            }
+
+            /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `quotient` or `remainder` don't have the same length as `src`
+            #[inline]
+            pub fn div_rem_into(&self, src: &[$primitive_type], quotient: &mut [$primitive_type], remainder: &mut [$primitive_type]) {
+                assert_eq!(src.len(), quotient.len());
+                assert_eq!(src.len(), remainder.len());
+                for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+                    let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+                    *q = numerator_quotient;
+                    *r = numerator_remainder;
+                }
+            }
+
+            /// Computes `ceil(numerator / self)`, the quotient rounded up to the nearest integer,
+            /// without needing a separate hardware division: just `div_rem` plus "add 1 if the
+            /// remainder was nonzero".
+            #[inline]
+            pub fn div_ceil(&self, numerator: $primitive_type) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, *self);
+                if remainder > 0 { quotient + 1 } else { quotient }
+            }
+
+            /// Rounds `numerator` up to the next multiple of this divisor.
+            #[inline]
+            pub fn next_multiple_of(&self, numerator: $primitive_type) -> $primitive_type {
+                self.div_ceil(numerator) * self.get()
+            }
+
+            /// Simultaneous floored integer division and modulus, for callers that want the
+            /// `num-integer` `div_mod_floor` call signature. Since this divisor is always
+            /// positive, this is identical to `div_rem`.
+            #[inline]
+            pub fn div_mod_floor(&self, numerator: $primitive_type) -> ($primitive_type, $primitive_type) {
+                Self::div_rem(numerator, *self)
+            }
+        }
+
+        impl Div<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                let multiplied = (self as $intermediate_type) * (rhs.multiplier as $intermediate_type);
+                let mulhi = (multiplied >> $bit_width) as $primitive_type;
+                let t = ((self.wrapping_sub(mulhi)) >> 1).wrapping_add(mulhi);
+                t >> rhs.shift_value
+            }
+        }
+
+        impl Rem<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                let quotient = self / rhs;
+                self - quotient * rhs.divisor
+            }
+        }
+
+        impl StrengthReducedDivisor for $struct_name {
+            type Primitive = $primitive_type;
+
+            #[inline]
+            fn new(divisor: Self::Primitive) -> Self {
+                Self::new(divisor)
+            }
+
+            #[inline]
+            fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+                Self::div_rem(numerator, denom)
+            }
+
+            #[inline]
+            fn get(&self) -> Self::Primitive {
+                Self::get(self)
+            }
+        }
+
+        // see the `num-traits` interop note near the top of the file
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $struct_name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.get().to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.get().to_u64()
+            }
+        }
+    )
+}
+
+// We have two separate macros because the two bigger versions seem to want to be optimized in a slightly different way than the two smaller ones
+strength_reduced_impl!(StrengthReducedU8, u8, u16, 8);
+strength_reduced_impl!(StrengthReducedU16, u16, u32, 16);
+strength_reduced_impl_intermediate_multiplier!(StrengthReducedU32, u32, u64, 32);
+strength_reduced_impl_intermediate_multiplier!(StrengthReducedU64, u64, u128, 64);
+
+// Our definition for usize will depend on how big usize is
+#[cfg(target_pointer_width = "16")]
+strength_reduced_impl!(StrengthReducedUsize, usize, u32, 16);
+#[cfg(target_pointer_width = "32")]
+strength_reduced_impl_intermediate_multiplier!(StrengthReducedUsize, usize, u64, 32);
+#[cfg(target_pointer_width = "64")]
+strength_reduced_impl_intermediate_multiplier!(StrengthReducedUsize, usize, u128, 64);
+
+strength_reduced_branchfree_impl!(StrengthReducedU8BranchFree, u8, u16, 8);
+strength_reduced_branchfree_impl!(StrengthReducedU16BranchFree, u16, u32, 16);
+strength_reduced_branchfree_impl!(StrengthReducedU32BranchFree, u32, u64, 32);
+strength_reduced_branchfree_impl!(StrengthReducedU64BranchFree, u64, u128, 64);
+
+#[cfg(target_pointer_width = "16")]
+strength_reduced_branchfree_impl!(StrengthReducedUsizeBranchFree, usize, u32, 16);
+#[cfg(target_pointer_width = "32")]
+strength_reduced_branchfree_impl!(StrengthReducedUsizeBranchFree, usize, u64, 32);
+#[cfg(target_pointer_width = "64")]
+strength_reduced_branchfree_impl!(StrengthReducedUsizeBranchFree, usize, u128, 64);
+
+
+// U128 has no native type twice its width, so it can't reuse either macro above: setup needs a
+// software 256-by-128 division to compute the magic multiplier, and the division hot path needs
+// the high 128 bits of a 128x128 product instead of a widening multiply the compiler can emit directly.
+
+// Computes the high 64 bits and low 64 bits of `a * b` for 64-bit `a` and `b`, via the schoolbook
+// 64x64->128 multiply that core already provides for free with `u128`.
+#[inline]
+fn mul_64_to_128(a: u64, b: u64) -> u128 {
+    (a as u128) * (b as u128)
+}
+
+// Computes the upper 128 bits of the full 256-bit product of two u128s, by splitting each operand
+// into hi/lo 64-bit limbs and summing the four partial products with carry propagation.
+#[inline]
+fn mulhi_u128(a: u128, b: u128) -> u128 {
+    let a_lo = a as u64;
+    let a_hi = (a >> 64) as u64;
+    let b_lo = b as u64;
+    let b_hi = (b >> 64) as u64;
+
+    let lo_lo = mul_64_to_128(a_lo, b_lo);
+    let hi_lo = mul_64_to_128(a_hi, b_lo);
+    let lo_hi = mul_64_to_128(a_lo, b_hi);
+    let hi_hi = mul_64_to_128(a_hi, b_hi);
+
+    // lo_lo's top 64 bits and the bottom 64 bits of each cross term all land at bit offset 64
+    let cross = (lo_lo >> 64) + (hi_lo & 0xffff_ffff_ffff_ffff) + (lo_hi & 0xffff_ffff_ffff_ffff);
+
+    hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64)
+}
+
+// Divides the 256-bit value `(numerator_hi, numerator_lo)` by `divisor`, assuming (as our caller
+// guarantees) that the quotient fits in 128 bits, and returns `(quotient, remainder)`.
+// Plain binary long division, one bit at a time -- setup-time-only code, so simplicity wins over speed here.
+fn divide_256_by_128(numerator_hi: u128, numerator_lo: u128, divisor: u128) -> (u128, u128) {
+    let mut remainder: u128 = 0;
+    let mut quotient: u128 = 0;
+    for i in (0..256).rev() {
+        let bit = if i >= 128 {
+            (numerator_hi >> (i - 128)) & 1
+        } else {
+            (numerator_lo >> i) & 1
+        };
+
+        // `remainder`'s own top bit, shifted out by the `<< 1` below, is effectively a 129th bit
+        // that the rest of this loop has to account for when comparing against `divisor`
+        let remainder_overflowed = (remainder >> 127) & 1 == 1;
+        remainder = (remainder << 1) | bit;
+
+        let quotient_bit = if remainder_overflowed || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            1
+        } else {
+            0
+        };
+        quotient = (quotient << 1) | quotient_bit;
+    }
+    (quotient, remainder)
+}
+
+/// Implements unsigned division and modulo via a 128x128->256 bit multiplication and shift.
+///
+/// Creating a an instance of this struct is more expensive than a single division, but if the division is repeated,
+/// this version will be several times faster than naive division.
+#[derive(Clone, Copy, Debug)]
+pub struct StrengthReducedU128 {
+    multiplier: u128,
+    divisor: u128,
+    shift_value: u8,
+}
+impl StrengthReducedU128 {
+    /// Creates a new divisor instance.
+    ///
+    /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for divison and remainders inside the loop.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `divisor` is 0
+    #[inline]
+    pub fn new(divisor: u128) -> Self {
+        assert!(divisor > 0);
+        if divisor == 1 {
+            // multiplier would need to be 2^128 here, which doesn't fit in a u128, so only its low
+            // 128 bits (0) are stored; the Div impl below adds the numerator back in for the dropped
+            // leading bit, and with a shift of 0 that returns the numerator unchanged
+            Self { multiplier: 0, divisor, shift_value: 0 }
+        } else {
+            // equivalent to divisor.next_power_of_two().trailing_zeros(), but next_power_of_two()
+            // itself would overflow for divisor > 2^127, since u128 has no room for a wider intermediate
+            let trailing_zeros = 128 - (divisor - 1).leading_zeros();
+
+            // The round-up magic number theorem (Hacker's Delight Theorem 10-2) wants a multiplier
+            // of ceil(2^(128 + trailing_zeros) / divisor), which can need 129 bits -- one more than
+            // a u128 can hold, and there's no wider primitive to borrow headroom from the way the
+            // other unsigned types do. So, same as `StrengthReducedU128BranchFree::new`: divide once
+            // at one exponent lower (guaranteed to fit in 128 bits), then double the quotient and
+            // remainder by hand to reach the exponent we actually want. Doubling the quotient with
+            // wrapping arithmetic drops exactly the leading bit that didn't fit; `div` below adds
+            // `self` back in to account for that dropped bit always being 1.
+            let half_exponent = 128 + trailing_zeros - 1;
+            let (half_hi, half_lo) = if half_exponent >= 128 {
+                (1u128 << (half_exponent - 128), 0u128)
+            } else {
+                (0u128, 1u128 << half_exponent)
+            };
+            let (half_quotient, half_remainder) = divide_256_by_128(half_hi, half_lo, divisor);
+
+            let remainder_doubling_overflowed = (half_remainder >> 127) & 1 == 1;
+            let mut full_remainder = half_remainder << 1;
+            let carry_bit = if remainder_doubling_overflowed || full_remainder >= divisor {
+                full_remainder = full_remainder.wrapping_sub(divisor);
+                1
+            } else {
+                0
+            };
+            let multiplier = half_quotient.wrapping_mul(2).wrapping_add(carry_bit);
+            let multiplier = if full_remainder > 0 { multiplier.wrapping_add(1) } else { multiplier };
+
+            Self {
+                multiplier,
+                divisor,
+                shift_value: trailing_zeros as u8,
+            }
+        }
+    }
+
+    /// Simultaneous truncated integer division and modulus.
+    /// Returns `(quotient, remainder)`.
+    #[inline]
+    pub fn div_rem(numerator: u128, denom: Self) -> (u128, u128) {
+        let quotient = numerator / denom;
+        let remainder = numerator - quotient * denom.divisor;
+        (quotient, remainder)
+    }
+
+    /// Retrieve the value used to create this struct
+    #[inline]
+    pub fn get(&self) -> u128 {
+        self.divisor
+    }
+
+    /// Divides every element of `slice` by this divisor, in place.
+    ///
+    /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+    /// with the `#[inline]` `Div` impl applied to each element in turn.
+    #[inline]
+    pub fn div_slice(&self, slice: &mut [u128]) {
+        for element in slice.iter_mut() {
+            *element = *element / *self;
+        }
+    }
+
+    /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+    #[inline]
+    pub fn rem_slice(&self, slice: &mut [u128]) {
+        for element in slice.iter_mut() {
+            *element = *element % *self;
+        }
+    }
+
+    /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `quotient` or `remainder` don't have the same length as `src`
+    #[inline]
+    pub fn div_rem_into(&self, src: &[u128], quotient: &mut [u128], remainder: &mut [u128]) {
+        assert_eq!(src.len(), quotient.len());
+        assert_eq!(src.len(), remainder.len());
+        for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+            let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+            *q = numerator_quotient;
+            *r = numerator_remainder;
+        }
+    }
+
+    /// Computes `ceil(numerator / self)`, the quotient rounded up to the nearest integer,
+    /// without needing a separate hardware division: just `div_rem` plus "add 1 if the
+    /// remainder was nonzero".
+    #[inline]
+    pub fn div_ceil(&self, numerator: u128) -> u128 {
+        let (quotient, remainder) = Self::div_rem(numerator, *self);
+        if remainder > 0 { quotient + 1 } else { quotient }
+    }
+
+    /// Rounds `numerator` up to the next multiple of this divisor.
+    #[inline]
+    pub fn next_multiple_of(&self, numerator: u128) -> u128 {
+        self.div_ceil(numerator) * self.get()
+    }
+
+    /// Simultaneous floored integer division and modulus, for callers that want the
+    /// `num-integer` `div_mod_floor` call signature. Since this divisor is always
+    /// positive, this is identical to `div_rem`.
+    #[inline]
+    pub fn div_mod_floor(&self, numerator: u128) -> (u128, u128) {
+        Self::div_rem(numerator, *self)
+    }
+}
+
+impl Div<StrengthReducedU128> for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn div(self, rhs: StrengthReducedU128) -> Self::Output {
+        // `rhs.multiplier` only stores the low 128 bits of the true (up to 129-bit) magic
+        // multiplier computed in `new()` -- its dropped leading bit is always 1, so adding
+        // `self` back onto the high half of the multiply reconstructs it. That addition can
+        // itself carry out of the high half's 128 bits; since the final quotient always fits
+        // in 128 bits, that carry is exactly the bit the subsequent shift brings back down.
+        let (multiplied, carry) = mulhi_u128(self, rhs.multiplier).overflowing_add(self);
+        // `shift_value` can be the full 128 (divisors above 2^127), which `>>` can't take
+        // directly -- shifting a 128-bit value right by its own width is always zero.
+        let shifted = if rhs.shift_value == 128 { 0 } else { multiplied >> rhs.shift_value };
+        if carry {
+            shifted | (1u128 << (128 - rhs.shift_value))
+        } else {
+            shifted
+        }
+    }
+}
+
+impl Rem<StrengthReducedU128> for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn rem(self, rhs: StrengthReducedU128) -> Self::Output {
+        let quotient = self / rhs;
+        self - quotient * rhs.divisor
+    }
+}
+
+impl StrengthReducedDivisor for StrengthReducedU128 {
+    type Primitive = u128;
+
+    #[inline]
+    fn new(divisor: Self::Primitive) -> Self {
+        Self::new(divisor)
+    }
+
+    #[inline]
+    fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+        Self::div_rem(numerator, denom)
+    }
+
+    #[inline]
+    fn get(&self) -> Self::Primitive {
+        Self::get(self)
+    }
+}
+
+// see the `num-traits` interop note near the top of the file
+#[cfg(feature = "num-traits")]
+impl num_traits::ToPrimitive for StrengthReducedU128 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+}
+
+/// Branch-free variant of [`StrengthReducedU128`], for use in loops the compiler should vectorize.
+/// See [`StrengthReducedU64BranchFree`] for details on the algorithm and its tradeoffs.
+///
+/// Creating a an instance of this struct is more expensive than a single division, but if the division is repeated,
+/// this version will be several times faster than naive division.
+#[derive(Clone, Copy, Debug)]
+pub struct StrengthReducedU128BranchFree {
+    multiplier: u128,
+    divisor: u128,
+    shift_value: u8,
+}
+impl StrengthReducedU128BranchFree {
+    /// Creates a new divisor instance.
+    ///
+    /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for divison and remainders inside the loop.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `divisor` is 0 or 1
+    #[inline]
+    pub fn new(divisor: u128) -> Self {
+        assert!(divisor > 1, "branch-free division cannot represent a divisor of 0 or 1");
+
+        let floor_log2 = 127 - divisor.leading_zeros();
+
+        if divisor.is_power_of_two() {
+            Self {
+                multiplier: 0,
+                divisor,
+                shift_value: (floor_log2 - 1) as u8,
+            }
+        } else {
+            // We want ceil(2^(128 + floor_log2 + 1) / divisor) - 2^128, i.e. the multiplier is 129 bits
+            // wide before truncating off its leading bit. Neither that power of two nor the 129-bit
+            // intermediate fit in a u128, so instead we compute the (128 + floor_log2)-bit half of the
+            // division, then double the quotient and remainder by hand; doubling the quotient with
+            // wrapping arithmetic drops exactly the leading bit we wanted to truncate anyway.
+            let half_exponent = 128 + floor_log2;
+            let (half_hi, half_lo) = if half_exponent >= 128 {
+                (1u128 << (half_exponent - 128), 0u128)
+            } else {
+                (0u128, 1u128 << half_exponent)
+            };
+            let (half_quotient, half_remainder) = divide_256_by_128(half_hi, half_lo, divisor);
+
+            let remainder_doubling_overflowed = (half_remainder >> 127) & 1 == 1;
+            let mut full_remainder = half_remainder << 1;
+            let carry_bit = if remainder_doubling_overflowed || full_remainder >= divisor {
+                full_remainder = full_remainder.wrapping_sub(divisor);
+                1
+            } else {
+                0
+            };
+            let multiplier = half_quotient.wrapping_mul(2).wrapping_add(carry_bit);
+            let multiplier = if full_remainder > 0 { multiplier.wrapping_add(1) } else { multiplier };
+
+            Self {
+                multiplier,
+                divisor,
+                shift_value: floor_log2 as u8,
+            }
+        }
+    }
+
+    /// Simultaneous truncated integer division and modulus.
+    /// Returns `(quotient, remainder)`.
+    #[inline]
+    pub fn div_rem(numerator: u128, denom: Self) -> (u128, u128) {
+        let quotient = numerator / denom;
+        let remainder = numerator - quotient * denom.divisor;
+        (quotient, remainder)
+    }
+
+    /// Retrieve the value used to create this struct
+    #[inline]
+    pub fn get(&self) -> u128 {
+        self.divisor
+    }
+
+    /// Divides every element of `slice` by this divisor, in place.
+    ///
+    /// Written as a tight loop over multiply-and-shift so the compiler can autovectorize it,
+    /// with the `#[inline]` `Div` impl applied to each element in turn.
+    #[inline]
+    pub fn div_slice(&self, slice: &mut [u128]) {
+        for element in slice.iter_mut() {
+            *element = *element / *self;
+        }
+    }
+
+    /// Replaces every element of `slice` with its remainder when divided by this divisor, in place.
+    #[inline]
+    pub fn rem_slice(&self, slice: &mut [u128]) {
+        for element in slice.iter_mut() {
+            *element = *element % *self;
+        }
+    }
+
+    /// Divides every element of `src` by this divisor, writing the quotients into `quotient` and the remainders into `remainder`.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `quotient` or `remainder` don't have the same length as `src`
+    #[inline]
+    pub fn div_rem_into(&self, src: &[u128], quotient: &mut [u128], remainder: &mut [u128]) {
+        assert_eq!(src.len(), quotient.len());
+        assert_eq!(src.len(), remainder.len());
+        for ((&numerator, q), r) in src.iter().zip(quotient.iter_mut()).zip(remainder.iter_mut()) {
+            let (numerator_quotient, numerator_remainder) = Self::div_rem(numerator, *self);
+            *q = numerator_quotient;
+            *r = numerator_remainder;
+        }
+    }
+
+    /// Computes `ceil(numerator / self)`, the quotient rounded up to the nearest integer,
+    /// without needing a separate hardware division: just `div_rem` plus "add 1 if the
+    /// remainder was nonzero".
+    #[inline]
+    pub fn div_ceil(&self, numerator: u128) -> u128 {
+        let (quotient, remainder) = Self::div_rem(numerator, *self);
+        if remainder > 0 { quotient + 1 } else { quotient }
+    }
+
+    /// Rounds `numerator` up to the next multiple of this divisor.
+    #[inline]
+    pub fn next_multiple_of(&self, numerator: u128) -> u128 {
+        self.div_ceil(numerator) * self.get()
+    }
+
+    /// Simultaneous floored integer division and modulus, for callers that want the
+    /// `num-integer` `div_mod_floor` call signature. Since this divisor is always
+    /// positive, this is identical to `div_rem`.
+    #[inline]
+    pub fn div_mod_floor(&self, numerator: u128) -> (u128, u128) {
+        Self::div_rem(numerator, *self)
+    }
+}
+
+impl Div<StrengthReducedU128BranchFree> for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn div(self, rhs: StrengthReducedU128BranchFree) -> Self::Output {
+        let mulhi = mulhi_u128(self, rhs.multiplier);
+        let t = ((self.wrapping_sub(mulhi)) >> 1).wrapping_add(mulhi);
+        t >> rhs.shift_value
+    }
+}
+
+impl Rem<StrengthReducedU128BranchFree> for u128 {
+    type Output = u128;
+
+    #[inline]
+    fn rem(self, rhs: StrengthReducedU128BranchFree) -> Self::Output {
+        let quotient = self / rhs;
+        self - quotient * rhs.divisor
+    }
+}
+
+impl StrengthReducedDivisor for StrengthReducedU128BranchFree {
+    type Primitive = u128;
+
+    #[inline]
+    fn new(divisor: Self::Primitive) -> Self {
+        Self::new(divisor)
+    }
+
+    #[inline]
+    fn div_rem(numerator: Self::Primitive, denom: Self) -> (Self::Primitive, Self::Primitive) {
+        Self::div_rem(numerator, denom)
+    }
+
+    #[inline]
+    fn get(&self) -> Self::Primitive {
+        Self::get(self)
+    }
+}
+
+// see the `num-traits` interop note near the top of the file
+#[cfg(feature = "num-traits")]
+impl num_traits::ToPrimitive for StrengthReducedU128BranchFree {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+}
+
+strength_reduced_signed_impl!(StrengthReducedI8, i8, u8, i16, 8);
+strength_reduced_signed_impl!(StrengthReducedI16, i16, u16, i32, 16);
+strength_reduced_signed_impl!(StrengthReducedI32, i32, u32, i64, 32);
+strength_reduced_signed_impl!(StrengthReducedI64, i64, u64, i128, 64);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use proptest::test_runner::Config;
+
+    macro_rules! reduction_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                let divisors = [1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        let expected_div = numerator / divisor;
+                        let expected_rem = numerator % divisor;
+
+                        let reduced_div = numerator / reduced_divisor;
+                        let reduced_rem = numerator % reduced_divisor;
+
+                        let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+
+                        assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    reduction_test!(test_strength_reduced_u8, StrengthReducedU8, u8);
+    reduction_test!(test_strength_reduced_u16, StrengthReducedU16, u16);
+    reduction_test!(test_strength_reduced_u32, StrengthReducedU32, u32);
+    reduction_test!(test_strength_reduced_u64, StrengthReducedU64, u64);
+    reduction_test!(test_strength_reduced_usize, StrengthReducedUsize, usize);
+    reduction_test!(test_strength_reduced_u128, StrengthReducedU128, u128);
+
+    // Signed division truncates toward zero and has its own MIN/-1/+1 edge cases, so it gets its
+    // own set of small and edge-case divisors and numerators instead of reusing reduction_test!
+    macro_rules! signed_reduction_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let min = core::$primitive_type::MIN;
+                let max = core::$primitive_type::MAX;
+                let divisors = [1,2,3,4,5,6,7,8,9,10,-1,-2,-3,-4,-5,min,min+1,max-1,max];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10,-1,-2,-3,-4,-5,min+1,max-1,max];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        let expected_div = numerator / divisor;
+                        let expected_rem = numerator % divisor;
+
+                        let reduced_div = numerator / reduced_divisor;
+                        let reduced_rem = numerator % reduced_divisor;
+
+                        let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+
+                        assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    signed_reduction_test!(test_strength_reduced_i8, StrengthReducedI8, i8);
+    signed_reduction_test!(test_strength_reduced_i16, StrengthReducedI16, i16);
+    signed_reduction_test!(test_strength_reduced_i32, StrengthReducedI32, i32);
+    signed_reduction_test!(test_strength_reduced_i64, StrengthReducedI64, i64);
+
+    // Branch-free dividers can't represent a divisor of 1, so they get their own divisor list
+    // instead of reusing reduction_test!
+    macro_rules! branchfree_reduction_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                let divisors = [2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        let expected_div = numerator / divisor;
+                        let expected_rem = numerator % divisor;
+
+                        let reduced_div = numerator / reduced_divisor;
+                        let reduced_rem = numerator % reduced_divisor;
+
+                        let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+
+                        assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    branchfree_reduction_test!(test_strength_reduced_u8_branchfree, StrengthReducedU8BranchFree, u8);
+    branchfree_reduction_test!(test_strength_reduced_u16_branchfree, StrengthReducedU16BranchFree, u16);
+    branchfree_reduction_test!(test_strength_reduced_u32_branchfree, StrengthReducedU32BranchFree, u32);
+    branchfree_reduction_test!(test_strength_reduced_u64_branchfree, StrengthReducedU64BranchFree, u64);
+    branchfree_reduction_test!(test_strength_reduced_usize_branchfree, StrengthReducedUsizeBranchFree, usize);
+    branchfree_reduction_test!(test_strength_reduced_u128_branchfree, StrengthReducedU128BranchFree, u128);
+
+    #[test]
+    #[should_panic]
+    fn test_strength_reduced_u64_branchfree_rejects_one() {
+        StrengthReducedU64BranchFree::new(1);
+    }
+
+    macro_rules! reduction_proptest {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            mod $test_name {
+                use super::*;
+                use proptest::sample::select;
+
+                fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let expected_div = numerator / divisor;
+                    let expected_rem = numerator % divisor;
+                    let reduced_div = numerator / reduced_divisor;
+                    let reduced_rem = numerator % reduced_divisor;
+                    assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                    assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                }
+
+
+
+                proptest! {
+                    #![proptest_config(Config::with_cases(100_000))]
+
+                    #[test]
+                    fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..core::$primitive_type::MAX, numerator in 0..core::$primitive_type::MAX) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+
+                    #[test]
+                    fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                            divisor in 1..core::$primitive_type::MAX,
+                            numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX])) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+
+                    #[test]
+                    fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                            divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX]),
+                            numerator in 0..core::$primitive_type::MAX) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+                }
+            }
+        )
+    }
+
+    reduction_proptest!(strength_reduced_u8, StrengthReducedU8, u8);
+    reduction_proptest!(strength_reduced_u16, StrengthReducedU16, u16);
+    reduction_proptest!(strength_reduced_u32, StrengthReducedU32, u32);
+    reduction_proptest!(strength_reduced_u64, StrengthReducedU64, u64);
+    reduction_proptest!(strength_reduced_usize, StrengthReducedUsize, usize);
+    reduction_proptest!(strength_reduced_u128, StrengthReducedU128, u128);
+
+    macro_rules! signed_reduction_proptest {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            mod $test_name {
+                use super::*;
+                use proptest::sample::select;
+
+                fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) -> Result<(), proptest::test_runner::TestCaseError> {
+                    // matches the panic native signed division gives for this one combination; nothing to compare against
+                    prop_assume!(!(divisor == -1 && numerator == core::$primitive_type::MIN));
+
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let expected_div = numerator / divisor;
+                    let expected_rem = numerator % divisor;
+                    let reduced_div = numerator / reduced_divisor;
+                    let reduced_rem = numerator % reduced_divisor;
+                    assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                    assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    Ok(())
+                }
+
+
+
+                proptest! {
+                    #![proptest_config(Config::with_cases(100_000))]
+
+                    #[test]
+                    fn fully_generated_inputs_are_div_rem_equivalent(divisor in core::$primitive_type::MIN..=core::$primitive_type::MAX, numerator in core::$primitive_type::MIN..=core::$primitive_type::MAX) {
+                        prop_assume!(divisor != 0);
+                        assert_div_rem_equivalence(divisor, numerator)?;
+                    }
+
+                    #[test]
+                    fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                            divisor in core::$primitive_type::MIN..=core::$primitive_type::MAX,
+                            numerator in select(vec![core::$primitive_type::MIN, core::$primitive_type::MIN + 1, -1 as $primitive_type, 0 as $primitive_type, 1 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX])) {
+                        prop_assume!(divisor != 0);
+                        assert_div_rem_equivalence(divisor, numerator)?;
+                    }
+
+                    #[test]
+                    fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                            divisor in select(vec![1 as $primitive_type, -1 as $primitive_type, 2 as $primitive_type, -2 as $primitive_type, core::$primitive_type::MIN, core::$primitive_type::MAX - 1, core::$primitive_type::MAX]),
+                            numerator in core::$primitive_type::MIN..=core::$primitive_type::MAX) {
+                        assert_div_rem_equivalence(divisor, numerator)?;
+                    }
+                }
+            }
+        )
+    }
+
+    signed_reduction_proptest!(strength_reduced_i8, StrengthReducedI8, i8);
+    signed_reduction_proptest!(strength_reduced_i16, StrengthReducedI16, i16);
+    signed_reduction_proptest!(strength_reduced_i32, StrengthReducedI32, i32);
+    signed_reduction_proptest!(strength_reduced_i64, StrengthReducedI64, i64);
+
+    macro_rules! branchfree_reduction_proptest {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            mod $test_name {
+                use super::*;
+                use proptest::sample::select;
+
+                fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let expected_div = numerator / divisor;
+                    let expected_rem = numerator % divisor;
+                    let reduced_div = numerator / reduced_divisor;
+                    let reduced_rem = numerator % reduced_divisor;
+                    assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                    assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                }
+
+
+
+                proptest! {
+                    #![proptest_config(Config::with_cases(100_000))]
+
+                    #[test]
+                    fn fully_generated_inputs_are_div_rem_equivalent(divisor in 2..core::$primitive_type::MAX, numerator in 0..core::$primitive_type::MAX) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+
+                    #[test]
+                    fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                            divisor in 2..core::$primitive_type::MAX,
+                            numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX])) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+
+                    #[test]
+                    fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                            divisor in select(vec![2 as $primitive_type, 3 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX]),
+                            numerator in 0..core::$primitive_type::MAX) {
+                        assert_div_rem_equivalence(divisor, numerator);
+                    }
+                }
+            }
+        )
+    }
+
+    branchfree_reduction_proptest!(strength_reduced_u8_branchfree, StrengthReducedU8BranchFree, u8);
+    branchfree_reduction_proptest!(strength_reduced_u16_branchfree, StrengthReducedU16BranchFree, u16);
+    branchfree_reduction_proptest!(strength_reduced_u32_branchfree, StrengthReducedU32BranchFree, u32);
+    branchfree_reduction_proptest!(strength_reduced_u64_branchfree, StrengthReducedU64BranchFree, u64);
+    branchfree_reduction_proptest!(strength_reduced_usize_branchfree, StrengthReducedUsizeBranchFree, usize);
+    branchfree_reduction_proptest!(strength_reduced_u128_branchfree, StrengthReducedU128BranchFree, u128);
+
+    macro_rules! reduction_spot_test {
+        ($test_name:ident, $struct_name:ident, $divisor:expr, $numerator:expr) => (
+            #[test]
+            fn $test_name() {
+                let divisor = $divisor;
+                let numerator = $numerator;
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div = numerator / divisor;
+                let expected_rem = numerator % divisor;
+                let reduced_div = numerator / reduced_divisor;
+                let reduced_rem = numerator % reduced_divisor;
+                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+            }
+        )
     }
 
     reduction_spot_test!(reduced_u8_spot_check_found_failure_case, StrengthReducedU8, 39, 233);
     reduction_spot_test!(reduced_u16_spot_check_found_failure_case, StrengthReducedU16, 3827, 49750);
+
+    macro_rules! slice_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                for &divisor in &[1, 2, 3, 7, max - 1, max] {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let src: std::vec::Vec<$primitive_type> = (0..20).map(|i| max - i).collect();
+
+                    let mut divided = src.clone();
+                    reduced_divisor.div_slice(&mut divided);
+
+                    let mut modulo = src.clone();
+                    reduced_divisor.rem_slice(&mut modulo);
+
+                    let mut quotient = std::vec![0; src.len()];
+                    let mut remainder = std::vec![0; src.len()];
+                    reduced_divisor.div_rem_into(&src, &mut quotient, &mut remainder);
+
+                    for (i, &numerator) in src.iter().enumerate() {
+                        assert_eq!(divided[i], numerator / divisor, "div_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(modulo[i], numerator % divisor, "rem_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(quotient[i], numerator / divisor, "div_rem_into quotient failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(remainder[i], numerator % divisor, "div_rem_into remainder failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    slice_test!(test_strength_reduced_u32_slice, StrengthReducedU32, u32);
+    slice_test!(test_strength_reduced_u64_slice, StrengthReducedU64, u64);
+    slice_test!(test_strength_reduced_u128_slice, StrengthReducedU128, u128);
+
+    // Branch-free dividers can't represent a divisor of 1, so they get their own divisor list
+    // instead of reusing slice_test!
+    macro_rules! branchfree_slice_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                for &divisor in &[2, 3, 7, max - 1, max] {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let src: std::vec::Vec<$primitive_type> = (0..20).map(|i| max - i).collect();
+
+                    let mut divided = src.clone();
+                    reduced_divisor.div_slice(&mut divided);
+
+                    let mut modulo = src.clone();
+                    reduced_divisor.rem_slice(&mut modulo);
+
+                    let mut quotient = std::vec![0; src.len()];
+                    let mut remainder = std::vec![0; src.len()];
+                    reduced_divisor.div_rem_into(&src, &mut quotient, &mut remainder);
+
+                    for (i, &numerator) in src.iter().enumerate() {
+                        assert_eq!(divided[i], numerator / divisor, "div_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(modulo[i], numerator % divisor, "rem_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(quotient[i], numerator / divisor, "div_rem_into quotient failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(remainder[i], numerator % divisor, "div_rem_into remainder failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    branchfree_slice_test!(test_strength_reduced_u8_branchfree_slice, StrengthReducedU8BranchFree, u8);
+    branchfree_slice_test!(test_strength_reduced_u16_branchfree_slice, StrengthReducedU16BranchFree, u16);
+    branchfree_slice_test!(test_strength_reduced_u32_branchfree_slice, StrengthReducedU32BranchFree, u32);
+    branchfree_slice_test!(test_strength_reduced_u64_branchfree_slice, StrengthReducedU64BranchFree, u64);
+    branchfree_slice_test!(test_strength_reduced_usize_branchfree_slice, StrengthReducedUsizeBranchFree, usize);
+    branchfree_slice_test!(test_strength_reduced_u128_branchfree_slice, StrengthReducedU128BranchFree, u128);
+
+    // Signed division truncates toward zero and has its own MIN/-1 edge case, so it gets its own
+    // slice/numerator data instead of reusing slice_test!
+    macro_rules! signed_slice_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let min = core::$primitive_type::MIN;
+                let max = core::$primitive_type::MAX;
+                for &divisor in &[1, 2, 3, 7, -1, -2, -7, min + 1, max - 1, max] {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let src: std::vec::Vec<$primitive_type> = (0..10).map(|i| max - i).chain((0..10).map(|i| min + 1 + i)).collect();
+
+                    let mut divided = src.clone();
+                    reduced_divisor.div_slice(&mut divided);
+
+                    let mut modulo = src.clone();
+                    reduced_divisor.rem_slice(&mut modulo);
+
+                    let mut quotient = std::vec![0; src.len()];
+                    let mut remainder = std::vec![0; src.len()];
+                    reduced_divisor.div_rem_into(&src, &mut quotient, &mut remainder);
+
+                    for (i, &numerator) in src.iter().enumerate() {
+                        assert_eq!(divided[i], numerator / divisor, "div_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(modulo[i], numerator % divisor, "rem_slice failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(quotient[i], numerator / divisor, "div_rem_into quotient failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(remainder[i], numerator % divisor, "div_rem_into remainder failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    signed_slice_test!(test_strength_reduced_i8_slice, StrengthReducedI8, i8);
+    signed_slice_test!(test_strength_reduced_i16_slice, StrengthReducedI16, i16);
+    signed_slice_test!(test_strength_reduced_i32_slice, StrengthReducedI32, i32);
+    signed_slice_test!(test_strength_reduced_i64_slice, StrengthReducedI64, i64);
+
+    macro_rules! rounding_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                // next_multiple_of can legitimately overflow for a numerator/divisor combination
+                // whose rounded-up result doesn't fit the primitive type (the same caveat the
+                // standard library documents for its own next_multiple_of), so this test keeps
+                // numerators modest instead of reusing reduction_test's MAX edge cases.
+                let divisors = [1,2,3,4,5,6,7,8,9,10,max-1,max];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        let expected_div_ceil = numerator / divisor + if numerator % divisor > 0 { 1 } else { 0 };
+                        let expected_div_mod_floor = (numerator / divisor, numerator % divisor);
+
+                        assert_eq!(expected_div_ceil, reduced_divisor.div_ceil(numerator), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div_ceil * divisor, reduced_divisor.next_multiple_of(numerator), "next_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div_mod_floor, reduced_divisor.div_mod_floor(numerator), "div_mod_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    rounding_test!(test_strength_reduced_u8_rounding, StrengthReducedU8, u8);
+    rounding_test!(test_strength_reduced_u32_rounding, StrengthReducedU32, u32);
+    rounding_test!(test_strength_reduced_u64_rounding, StrengthReducedU64, u64);
+    rounding_test!(test_strength_reduced_u128_rounding, StrengthReducedU128, u128);
+
+    // Branch-free dividers can't represent a divisor of 1, so they get their own divisor list
+    // instead of reusing rounding_test!
+    macro_rules! branchfree_rounding_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = core::$primitive_type::MAX;
+                let divisors = [2,3,4,5,6,7,8,9,10,max-1,max];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        let expected_div_ceil = numerator / divisor + if numerator % divisor > 0 { 1 } else { 0 };
+                        let expected_div_mod_floor = (numerator / divisor, numerator % divisor);
+
+                        assert_eq!(expected_div_ceil, reduced_divisor.div_ceil(numerator), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div_ceil * divisor, reduced_divisor.next_multiple_of(numerator), "next_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_div_mod_floor, reduced_divisor.div_mod_floor(numerator), "div_mod_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    branchfree_rounding_test!(test_strength_reduced_u32_branchfree_rounding, StrengthReducedU32BranchFree, u32);
+    branchfree_rounding_test!(test_strength_reduced_u128_branchfree_rounding, StrengthReducedU128BranchFree, u128);
+
+    #[test]
+    #[should_panic]
+    fn test_div_rem_into_mismatched_lengths_panics() {
+        let reduced_divisor = StrengthReducedU32::new(7);
+        let src = [1u32, 2, 3];
+        let mut quotient = [0u32; 3];
+        let mut remainder = [0u32; 2];
+        reduced_divisor.div_rem_into(&src, &mut quotient, &mut remainder);
+    }
+
+    // Exercises the StrengthReducedDivisor trait through a single generic function, rather than
+    // the concrete types directly, since that's the whole point of the trait existing.
+    fn generic_div_rem<D: StrengthReducedDivisor>(numerator: D::Primitive, divisor: D::Primitive) -> (D::Primitive, D::Primitive) {
+        let reduced_divisor = D::new(divisor);
+        let quotient = numerator / reduced_divisor;
+        let remainder = numerator % reduced_divisor;
+        (quotient, remainder)
+    }
+
+    #[test]
+    fn test_strength_reduced_divisor_trait_is_generic() {
+        assert_eq!(generic_div_rem::<StrengthReducedU32>(17, 5), (3, 2));
+        assert_eq!(generic_div_rem::<StrengthReducedU64>(17, 5), (3, 2));
+        assert_eq!(generic_div_rem::<StrengthReducedU128>(17, 5), (3, 2));
+        assert_eq!(generic_div_rem::<StrengthReducedI32>(-17, 5), (-3, -2));
+        assert_eq!(generic_div_rem::<StrengthReducedU32BranchFree>(17, 5), (3, 2));
+
+        let reduced_divisor = StrengthReducedU32::new(5);
+        assert_eq!(reduced_divisor.get(), 5);
+    }
 }